@@ -281,7 +281,7 @@ fn run_app<B: ratatui::backend::Backend>(
 
                             // Process the input
                             let node = flow.nodes.get(&current_node_id).unwrap();
-                            
+
                             const EXIT_COMMANDS: [&str; 4] = ["no", "bye", "quit", "exit"];
 
                             let next_id = node.options.iter()
@@ -303,24 +303,55 @@ fn run_app<B: ratatui::backend::Backend>(
                                 current_node_id = node.next_node.as_ref().unwrap().clone();
                                 waiting_for_input = false;
                             } else {
-                                let available_options: Vec<&String> = node.options.keys().collect();
-                                let options_list = match available_options.len() {
-                                    0 => String::from("No options available."),
-                                    1 => format!("'{}'", available_options[0]),
-                                    _ => {
-                                        let last_index = available_options.len() - 1;
-                                        let initial_part = available_options[0..last_index]
-                                            .iter()
-                                            .map(|k| format!("'{}'", k))
-                                            .collect::<Vec<String>>()
-                                            .join(", ");
-                                        format!("{} or '{}'", initial_part, available_options[last_index])
+                                // The literal prefix match missed, so fall back to a fuzzy
+                                // pass over the option keys. Auto-select only when the best
+                                // candidate clears the threshold and beats the runner-up by
+                                // a clear margin; otherwise surface the closest guesses.
+                                let ranked = rank_options(&user_input, &node.options);
+
+                                let auto_pick = match (ranked.first(), ranked.get(1)) {
+                                    (Some(top), Some(runner)) => {
+                                        if top.2 >= FUZZY_THRESHOLD && top.2 - runner.2 >= FUZZY_MARGIN {
+                                            Some(top.1.clone())
+                                        } else {
+                                            None
+                                        }
+                                    }
+                                    (Some(top), None) => {
+                                        if top.2 >= FUZZY_THRESHOLD {
+                                            Some(top.1.clone())
+                                        } else {
+                                            None
+                                        }
                                     }
+                                    _ => None,
                                 };
-                                app.add_message(
-                                    format!("I'm sorry, I didn't understand that. Please choose from: {}", options_list),
-                                    true,
-                                );
+
+                                if let Some(id) = auto_pick {
+                                    current_node_id = id;
+                                    waiting_for_input = false;
+                                } else if !ranked.is_empty() {
+                                    let suggestions: Vec<&String> =
+                                        ranked.iter().take(3).map(|(key, _, _)| key).collect();
+                                    app.add_message(
+                                        format!(
+                                            "I'm not quite sure what you meant. Did you mean {}?",
+                                            format_option_list(&suggestions)
+                                        ),
+                                        true,
+                                    );
+                                } else {
+                                    let available_options: Vec<&String> = node.options.keys().collect();
+                                    let options_list = if available_options.is_empty() {
+                                        String::from("No options available.")
+                                    } else {
+                                        format_option_list(&available_options)
+                                    };
+                                    app.add_message(
+                                        format!("I'm sorry, I didn't understand that. Please choose from: {}", options_list),
+                                        true,
+                                    );
+                                }
                             }
                         }
                     }
@@ -509,6 +540,131 @@ fn render_message(f: &mut Frame, area: Rect, msg: &ChatMessage, y_offset: u16, p
     msg_height + gap // Return total height including gap
 }
 
+// Minimum normalized score a candidate must reach before it can be
+// auto-selected, and the margin by which it must beat the runner-up.
+const FUZZY_THRESHOLD: f64 = 0.5;
+const FUZZY_MARGIN: f64 = 0.15;
+
+// Score `query` against `candidate` as a subsequence with a gap penalty.
+// The query characters must appear, in order, inside the candidate;
+// consecutive matches and matches at word boundaries earn extra points,
+// skipped candidate characters cost a small penalty, and the total is
+// divided by candidate length, so shorter keys score higher for the same
+// matches. Returns `None` when the query is not a subsequence of the
+// candidate.
+fn subsequence_score(query: &str, candidate: &str) -> Option<f64> {
+    let q: Vec<char> = query.chars().collect();
+    let c: Vec<char> = candidate.chars().collect();
+    if q.is_empty() || c.is_empty() {
+        return None;
+    }
+
+    let mut score = 0.0_f64;
+    let mut ci = 0usize;
+    let mut prev_match: Option<usize> = None;
+
+    for &qc in &q {
+        let mut matched = None;
+        while ci < c.len() {
+            if c[ci].eq_ignore_ascii_case(&qc) {
+                matched = Some(ci);
+                ci += 1;
+                break;
+            }
+            ci += 1;
+        }
+        let idx = matched?;
+
+        score += 1.0;
+        // Reward runs of adjacent matches.
+        if prev_match == Some(idx.wrapping_sub(1)) {
+            score += 1.0;
+        }
+        // Reward matches that land on a word boundary.
+        if idx == 0 || matches!(c.get(idx - 1), Some(' ') | Some('_') | Some('-')) {
+            score += 0.5;
+        }
+        prev_match = Some(idx);
+    }
+
+    // Penalize candidate characters the query skipped over, then normalize.
+    let skipped = c.len().saturating_sub(q.len()) as f64;
+    score -= 0.05 * skipped;
+    Some(score / c.len() as f64)
+}
+
+// Classic Levenshtein edit distance via a DP table over the two strings.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut dp: Vec<usize> = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        let mut prev = dp[0];
+        dp[0] = i;
+        for j in 1..=b.len() {
+            let tmp = dp[j];
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            dp[j] = (dp[j] + 1).min(dp[j - 1] + 1).min(prev + cost);
+            prev = tmp;
+        }
+    }
+
+    dp[b.len()]
+}
+
+// Rank a node's option keys against the user's reply, best first. Keys that
+// match as a subsequence are scored directly; keys that only match within a
+// close edit distance (<= 2) get a fixed score: FUZZY_THRESHOLD for distance
+// <= 1, so they can still be auto-selected, and just below it for distance 2,
+// so they can only be offered as suggestions. Keys further away are dropped.
+// Each entry is `(key, next_node_id, score)`.
+fn rank_options(query: &str, options: &HashMap<String, String>) -> Vec<(String, String, f64)> {
+    let mut scored: Vec<(String, String, f64)> = Vec::new();
+
+    for (key, next_node_id) in options {
+        let candidate = key.to_lowercase();
+        if let Some(score) = subsequence_score(query, &candidate) {
+            scored.push((key.clone(), next_node_id.clone(), score));
+        } else {
+            let dist = levenshtein(query, &candidate);
+            if dist <= 2 {
+                // Keep a distance-1 typo at the auto-select threshold so a
+                // dominant single-char slip still navigates (the runner-up
+                // margin guard prevents mis-navigation), and drop a distance-2
+                // match just below it so it can only be offered as a suggestion.
+                let score = if dist <= 1 {
+                    FUZZY_THRESHOLD
+                } else {
+                    FUZZY_THRESHOLD - 0.1
+                };
+                scored.push((key.clone(), next_node_id.clone(), score));
+            }
+        }
+    }
+
+    scored.sort_by(|a, b| b.2.partial_cmp(&a.2).unwrap_or(std::cmp::Ordering::Equal));
+    scored
+}
+
+// Render option keys as a human-readable list: "'a'", "'a' or 'b'", or
+// "'a', 'b' or 'c'".
+fn format_option_list(keys: &[&String]) -> String {
+    match keys.len() {
+        0 => String::new(),
+        1 => format!("'{}'", keys[0]),
+        _ => {
+            let last_index = keys.len() - 1;
+            let initial_part = keys[0..last_index]
+                .iter()
+                .map(|k| format!("'{}'", k))
+                .collect::<Vec<String>>()
+                .join(", ");
+            format!("{} or '{}'", initial_part, keys[last_index])
+        }
+    }
+}
+
 fn wrap_text(text: &str, max_width: usize) -> Vec<String> {
     let mut lines = Vec::new();
     let mut current_line = String::new();